@@ -0,0 +1,14 @@
+/// The transition from one option-like value to another.
+#[derive(Ord, PartialOrd, Eq, PartialEq, Hash, Clone, Copy, Debug)]
+pub enum Diff {
+    /// Both values are empty
+    NoChange,
+    /// The old value is empty, the new value is not
+    Added,
+    /// The old value is not empty, the new value is
+    Removed,
+    /// Both values are not empty and equal
+    Same,
+    /// Both values are not empty and different
+    Changed,
+}
@@ -0,0 +1,6 @@
+/// A value of one of two types.
+#[derive(Ord, PartialOrd, Eq, PartialEq, Hash, Clone, Copy, Debug)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
@@ -35,6 +35,35 @@
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+mod diff;
+mod either;
+mod option_like;
+mod option_like_iterator_ext;
+mod variant_debug;
+
+pub use diff::*;
+pub use either::*;
+pub use option_like::*;
+pub use option_like_iterator_ext::*;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::variant_debug::VariantDebug;
+
+    #[cfg(feature = "alloc")]
+    pub use alloc::{format, string::String, vec::Vec};
+    #[cfg(feature = "heapless")]
+    pub use heapless;
+    #[cfg(feature = "std")]
+    pub use std::{collections::HashMap, eprintln, time::Instant};
+}
+
 /// Creates a new enum type that behaves like Rust's `Option<T>` but with custom names.
 ///
 /// This macro allows you to create your own Option-like enum with customized names for the variants
@@ -49,6 +78,23 @@
 /// - `$none`: Name of the empty variant (e.g., `Miss`)
 /// - `is_some => $is_some`: Name of the method that checks if the enum holds a value (e.g., `is_hit`)
 /// - `is_none => $is_none`: Name of the method that checks if the enum is empty (e.g., `is_miss`)
+///
+/// # Features
+///
+/// - `alloc`: Enables the methods that allocate (e.g., `ok_or_msg`)
+/// - `std`: Enables the methods that depend on the standard library (e.g., `count_by`); `debug_tap` additionally requires `debug_assertions` in the invoking crate, so it is not available in release builds
+/// - `heapless`: Enables the methods that collect into fixed-capacity `heapless` collections (e.g., `collect_hits_heapless`)
+///
+/// # Clauses
+///
+/// The following optional clauses may be appended after `is_none => $is_none`:
+///
+/// - `lazy => $lazy`: Generates a `$lazy<T, F>` struct that evaluates `F: FnOnce() -> Option<T>` on first access and caches the result as `$name<T>`
+/// - `ops`: Implements `BitOr` (delegates to `or`) and `BitAnd` (delegates to `and`) for `$name<T>`
+/// - `double_buffer => $buffer`: Generates a `$buffer<T>` struct that holds a front and a back `$name<T>`; `swap_and_take` swaps them and takes the old front, leaving `$none` in the back
+/// - `into => $wrapper`: Implements `From<$name<T>>` for `$wrapper<T>` via `From<Option<T>>`, which `$wrapper<T>` must implement
+/// - `eq_inner`: Implements `PartialEq<T>` for `$name<T>`, so that `$some(x)` equals `x` and `$none` equals nothing
+/// - `ring => $ring`: Generates a `$ring<T, const N: usize>` struct that holds `N` slots of `$name<T>`; `push` stores a value at a rotating index and returns the evicted slot
 #[macro_export]
 macro_rules! option_like {
     (
@@ -62,6 +108,8 @@ macro_rules! option_like {
 
         is_some => $is_some:ident
         is_none => $is_none:ident
+
+        $($clauses:tt)*
     ) => {
         $(#[$meta])*
         $vis enum $name<T> {
@@ -139,113 +187,2034 @@ macro_rules! option_like {
                     $none => expect_failed(msg),
                 }
             }
-        }
 
-        impl<T> From<Option<T>> for $name<T> {
-            fn from(value: Option<T>) -> Self {
-                match value {
-                    Some(inner) => $some(inner),
-                    None => $none
+            #[inline]
+            pub fn filter_then_map<P, U, F>(self, predicate: P, f: F) -> $name<U>
+            where
+                P: FnOnce(&T) -> bool,
+                F: FnOnce(T) -> U,
+            {
+                match self {
+                    $some(x) if predicate(&x) => $some(f(x)),
+                    _ => $none,
                 }
             }
-        }
 
-        impl<T> From<$name<T>> for Option<T> {
-            fn from(value: $name<T>) -> Option<T> {
-                match value {
-                    $some(inner) => Some(inner),
-                    $none => None
+            #[inline]
+            pub fn as_option_deref(&self) -> Option<&T::Target>
+            where
+                T: core::ops::Deref,
+            {
+                match self {
+                    $some(x) => Some(x.deref()),
+                    $none => None,
                 }
             }
-        }
 
-        #[cold]
-        #[track_caller]
-        const fn unwrap_failed() -> ! {
-            panic!(stringify!("called `", $name, "::unwrap()` on a `", $none, "` value"))
-        }
+            #[inline]
+            pub fn and<U>(self, other: $name<U>) -> $name<U> {
+                match self {
+                    $some(_) => other,
+                    $none => $none,
+                }
+            }
 
-        #[cold]
-        #[track_caller]
-        const fn expect_failed(msg: &str) -> ! {
-            panic!("{}", msg)
-        }
-    };
-}
+            #[inline]
+            pub fn or(self, other: Self) -> Self {
+                match self {
+                    $some(x) => $some(x),
+                    $none => other,
+                }
+            }
 
-#[cfg(test)]
-mod tests {
-    option_like!(
-        #[derive(Ord, PartialOrd, Eq, PartialEq, Default, Clone, Debug)]
-        enum Cached<T> {
-            Hit(T),
-            #[default]
-            Miss,
-        }
+            #[inline]
+            pub fn sum_hits<I>(iter: I) -> T
+            where
+                I: IntoIterator<Item = Self>,
+                T: core::iter::Sum,
+            {
+                iter.into_iter().filter_map(Option::<T>::from).sum()
+            }
 
-        is_some => is_hit
-        is_none => is_miss
-    );
+            #[doc = concat!("Returns `", stringify!($some), "(value)` if `valid(&value)` returns `true`, otherwise drops the value and returns `", stringify!($none), "`.")]
+            #[inline]
+            pub fn try_new<F>(value: T, valid: F) -> Self
+            where
+                F: FnOnce(&T) -> bool,
+            {
+                if valid(&value) { $some(value) } else { $none }
+            }
 
-    fn hit() -> Cached<bool> {
-        Hit(true)
-    }
+            #[inline]
+            pub fn and_then_result<U, E, F>(self, f: F) -> ::core::result::Result<$name<U>, E>
+            where
+                F: FnOnce(T) -> ::core::result::Result<$name<U>, E>,
+            {
+                match self {
+                    $some(x) => f(x),
+                    $none => ::core::result::Result::Ok($none),
+                }
+            }
 
-    fn miss() -> Cached<bool> {
-        Miss
-    }
+            #[inline]
+            pub fn labeled(&self) -> (&'static str, Option<&T>) {
+                match self {
+                    $some(x) => (stringify!($some), Some(x)),
+                    $none => (stringify!($none), None),
+                }
+            }
 
-    #[test]
-    fn test_boolean_methods() {
-        assert!(hit().is_hit());
-        assert!(miss().is_miss());
-    }
+            #[inline]
+            #[track_caller]
+            pub fn expect_display(self, msg: impl core::fmt::Display) -> T {
+                match self {
+                    $some(val) => val,
+                    $none => expect_display_failed(msg),
+                }
+            }
 
-    #[test]
-    fn test_from() {
-        assert_eq!(Option::<bool>::from(hit()), Some(true));
-        assert_eq!(Option::<bool>::from(miss()), None);
-        assert_eq!(Cached::<bool>::from(Some(true)), Hit(true));
-        assert_eq!(Cached::<bool>::from(None), Miss);
-    }
+            #[inline]
+            pub fn fold_hits<A, F, I>(iter: I, init: A, f: F) -> A
+            where
+                F: FnMut(A, T) -> A,
+                I: IntoIterator<Item = Self>,
+            {
+                iter.into_iter().filter_map(Option::<T>::from).fold(init, f)
+            }
 
-    #[test]
-    fn test_map() {
-        assert_eq!(hit().map(|t| !t), Hit(false));
-        assert_eq!(miss().map(|t| !t), Miss);
-    }
+            #[doc = concat!("Replaces the value of `", stringify!($some), "` with `value` if `predicate` returns `true` for the current value, returning whether the replacement occurred. On `", stringify!($none), "`, stores `value` and returns `false`.")]
+            #[inline]
+            pub fn replace_if<P>(&mut self, predicate: P, value: T) -> bool
+            where
+                P: FnOnce(&T) -> bool,
+            {
+                match self {
+                    $some(x) => {
+                        let replaced = predicate(x);
+                        if replaced {
+                            *x = value;
+                        }
+                        replaced
+                    }
+                    $none => {
+                        *self = $some(value);
+                        false
+                    }
+                }
+            }
 
-    #[test]
-    fn test_unwrap_or_default() {
-        assert!(hit().unwrap_or_default());
-        assert!(!miss().unwrap_or_default());
-    }
+            #[doc = concat!("Returns `true` if both values are `", stringify!($some), "` and differ by at most `epsilon`, or if both are `", stringify!($none), "`. For integer payloads whose difference does not fit in `T` (e.g. `i32::MIN` and `i32::MAX`), the subtraction overflows.")]
+            #[inline]
+            pub fn approx_eq(&self, other: &Self, epsilon: T) -> bool
+            where
+                T: core::ops::Sub<Output = T> + PartialOrd + Copy,
+            {
+                match (self, other) {
+                    ($some(a), $some(b)) => {
+                        let delta = if a >= b { *a - *b } else { *b - *a };
+                        delta <= epsilon
+                    }
+                    ($none, $none) => true,
+                    _ => false,
+                }
+            }
 
-    #[test]
-    fn test_unwrap_or_else() {
-        assert!(hit().unwrap_or_else(|| false));
-        assert!(miss().unwrap_or_else(|| true));
-    }
+            #[inline]
+            pub fn map_to_option<U, F>(self, f: F) -> Option<U>
+            where
+                F: FnOnce(T) -> U,
+            {
+                match self {
+                    $some(x) => Some(f(x)),
+                    $none => None,
+                }
+            }
 
-    #[test]
-    fn test_unwrap_no_panic() {
-        assert!(hit().unwrap());
-    }
+            #[inline]
+            pub fn with_inner<U, F>(&self, f: F) -> Option<U>
+            where
+                F: FnOnce(&T) -> U,
+            {
+                match self {
+                    $some(x) => Some(f(x)),
+                    $none => None,
+                }
+            }
 
-    #[test]
-    #[should_panic]
-    fn test_unwrap_panic() {
-        miss().unwrap();
-    }
+            #[inline]
+            pub fn ok_or_else_log<E, F, L>(self, log: L, err: F) -> ::core::result::Result<T, E>
+            where
+                F: FnOnce() -> E,
+                L: FnOnce(),
+            {
+                match self {
+                    $some(x) => ::core::result::Result::Ok(x),
+                    $none => {
+                        log();
+                        ::core::result::Result::Err(err())
+                    }
+                }
+            }
 
-    #[test]
-    fn test_expect_no_panic() {
-        assert!(hit().expect("should not panic"));
-    }
+            #[inline]
+            pub fn drain_once(&mut self) -> impl Iterator<Item = T> + use<T> {
+                Option::<T>::from(core::mem::replace(self, $none)).into_iter()
+            }
 
-    #[test]
-    #[should_panic]
-    fn test_expect_panic() {
-        miss().expect("should panic");
+            #[inline]
+            pub fn entry_mut(&mut self) -> (bool, &mut T)
+            where
+                T: Default,
+            {
+                let inserted = self.$is_none();
+                if inserted {
+                    *self = $some(T::default());
+                }
+                match self {
+                    $some(x) => (inserted, x),
+                    $none => unreachable!(),
+                }
+            }
+
+            #[inline]
+            pub fn and_then_map<M, U, A, B>(self, a: A, b: B) -> $name<U>
+            where
+                A: FnOnce(T) -> $name<M>,
+                B: FnOnce(M) -> U,
+            {
+                match self {
+                    $some(x) => a(x).map(b),
+                    $none => $none,
+                }
+            }
+
+            #[inline]
+            pub fn zip_or<U, E>(self, other: $name<U>, err: E) -> ::core::result::Result<(T, U), E> {
+                match (self, other) {
+                    ($some(a), $some(b)) => ::core::result::Result::Ok((a, b)),
+                    _ => ::core::result::Result::Err(err),
+                }
+            }
+
+            #[inline]
+            pub fn zip_or_else<U, E, F>(self, other: $name<U>, err: F) -> ::core::result::Result<(T, U), E>
+            where
+                F: FnOnce() -> E,
+            {
+                match (self, other) {
+                    ($some(a), $some(b)) => ::core::result::Result::Ok((a, b)),
+                    _ => ::core::result::Result::Err(err()),
+                }
+            }
+
+            #[inline]
+            pub fn map_or_insert<F>(&mut self, default: T, f: F)
+            where
+                F: FnOnce(T) -> T,
+            {
+                *self = match core::mem::replace(self, $none) {
+                    $some(x) => $some(f(x)),
+                    $none => $some(default),
+                };
+            }
+
+            #[inline]
+            pub fn debug_miss_only(&self) -> impl core::fmt::Debug + '_ {
+                match self {
+                    $some(_) => $crate::__private::VariantDebug {
+                        name: stringify!($some),
+                        has_value: true,
+                    },
+                    $none => $crate::__private::VariantDebug {
+                        name: stringify!($none),
+                        has_value: false,
+                    },
+                }
+            }
+
+            #[inline]
+            pub fn continue_on_hit(self) -> core::ops::ControlFlow<(), T> {
+                match self {
+                    $some(x) => core::ops::ControlFlow::Continue(x),
+                    $none => core::ops::ControlFlow::Break(()),
+                }
+            }
+
+            #[inline]
+            pub fn break_on_hit(self) -> core::ops::ControlFlow<T, ()> {
+                match self {
+                    $some(x) => core::ops::ControlFlow::Break(x),
+                    $none => core::ops::ControlFlow::Continue(()),
+                }
+            }
+
+            #[inline]
+            pub fn get_or_default(&self) -> T
+            where
+                T: Default + Clone,
+            {
+                match self {
+                    $some(x) => x.clone(),
+                    $none => T::default(),
+                }
+            }
+
+            #[inline]
+            pub fn as_ref_ok_or<E>(&self, err: E) -> ::core::result::Result<&T, E> {
+                match self {
+                    $some(x) => ::core::result::Result::Ok(x),
+                    $none => ::core::result::Result::Err(err),
+                }
+            }
+
+            #[inline]
+            pub fn as_ref_ok_or_else<E, F>(&self, err: F) -> ::core::result::Result<&T, E>
+            where
+                F: FnOnce() -> E,
+            {
+                match self {
+                    $some(x) => ::core::result::Result::Ok(x),
+                    $none => ::core::result::Result::Err(err()),
+                }
+            }
+
+            #[inline]
+            pub fn update<F>(self, f: F) -> Self
+            where
+                F: FnOnce(T) -> T,
+            {
+                self.map(f)
+            }
+
+            #[inline]
+            pub const fn variant_name(&self) -> &'static str {
+                match self {
+                    $some(_) => stringify!($some),
+                    $none => stringify!($none),
+                }
+            }
+
+            #[doc = concat!("Returns `", stringify!($some), "(T::default())` for `\"", stringify!($some), "\"`, `", stringify!($none), "` for `\"", stringify!($none), "\"`, and `None` for any other string.")]
+            #[inline]
+            pub fn from_variant_name(name: &str) -> Option<Self>
+            where
+                T: Default,
+            {
+                match name {
+                    stringify!($some) => Some($some(T::default())),
+                    stringify!($none) => Some($none),
+                    _ => None,
+                }
+            }
+
+            #[inline]
+            pub fn observe<H>(self, hook: H) -> Self
+            where
+                H: FnOnce(bool),
+            {
+                hook(self.$is_some());
+                self
+            }
+
+            #[inline]
+            pub fn into_std_option(self) -> Option<T> {
+                self.into()
+            }
+
+            #[inline]
+            pub fn map_from_std(value: Option<T>) -> Self {
+                value.into()
+            }
+
+            #[inline]
+            pub fn compare_and_set(&mut self, expected: &T, new: T) -> bool
+            where
+                T: PartialEq,
+            {
+                match self {
+                    $some(x) if x == expected => {
+                        *x = new;
+                        true
+                    }
+                    _ => false,
+                }
+            }
+
+            #[inline]
+            #[track_caller]
+            pub fn expect_ref(&self, msg: &str) -> &T {
+                match self {
+                    $some(val) => val,
+                    $none => expect_failed(msg),
+                }
+            }
+
+            #[inline]
+            pub fn cloned_option(&self) -> Option<T>
+            where
+                T: Clone,
+            {
+                match self {
+                    $some(x) => Some(x.clone()),
+                    $none => None,
+                }
+            }
+
+            #[inline]
+            pub const fn repeat_none<const N: usize>() -> [Self; N] {
+                [const { $none }; N]
+            }
+
+            #[inline]
+            pub fn set_if_none(&mut self, value: T) -> bool {
+                match self {
+                    $some(_) => false,
+                    $none => {
+                        *self = $some(value);
+                        true
+                    }
+                }
+            }
+
+            #[inline]
+            pub fn first(&self) -> Option<&T> {
+                match self {
+                    $some(x) => Some(x),
+                    $none => None,
+                }
+            }
+
+            #[inline]
+            pub fn last(&self) -> Option<&T> {
+                self.first()
+            }
+
+            #[inline]
+            pub fn from_array([value]: [T; 1]) -> Self {
+                $some(value)
+            }
+
+            #[inline]
+            pub fn from_slice_first(slice: &[T]) -> Self
+            where
+                T: Clone,
+            {
+                slice.first().cloned().into()
+            }
+
+            #[doc = concat!("Returns the value of `", stringify!($some), "`, or calls `f` with the reason of `", stringify!($none), "`. The reason is `()` because `", stringify!($none), "` carries no data.")]
+            #[inline]
+            #[track_caller]
+            pub fn unwrap_or_else_reason<F>(self, f: F) -> T
+            where
+                F: FnOnce(()) -> T,
+            {
+                match self {
+                    $some(x) => x,
+                    $none => f(()),
+                }
+            }
+
+            #[inline]
+            pub fn take_map<U, F>(&mut self, f: F) -> $name<U>
+            where
+                F: FnOnce(T) -> U,
+            {
+                core::mem::replace(self, $none).map(f)
+            }
+
+            #[doc = concat!("Compares two values, ordering `", stringify!($none), "` relative to `", stringify!($some), "` according to `none_is`. Two `", stringify!($some), "` values compare by their inner values, two `", stringify!($none), "` values are equal.")]
+            #[inline]
+            pub fn cmp_with_none_policy(&self, other: &Self, none_is: core::cmp::Ordering) -> core::cmp::Ordering
+            where
+                T: Ord,
+            {
+                match (self, other) {
+                    ($some(a), $some(b)) => a.cmp(b),
+                    ($none, $none) => core::cmp::Ordering::Equal,
+                    ($none, $some(_)) => none_is,
+                    ($some(_), $none) => none_is.reverse(),
+                }
+            }
+
+            #[inline]
+            pub fn first_some<I>(iter: I) -> Self
+            where
+                I: IntoIterator<Item = Option<T>>,
+            {
+                iter.into_iter().flatten().next().into()
+            }
+
+            #[inline]
+            pub fn as_mut_map<U, F>(&mut self, f: F) -> Option<U>
+            where
+                F: FnOnce(&mut T) -> U,
+            {
+                match self {
+                    $some(x) => Some(f(x)),
+                    $none => None,
+                }
+            }
+
+            #[inline]
+            #[allow(clippy::result_unit_err)]
+            pub fn into_either(self) -> ::core::result::Result<T, ()> {
+                match self {
+                    $some(x) => ::core::result::Result::Ok(x),
+                    $none => ::core::result::Result::Err(()),
+                }
+            }
+
+            #[inline]
+            #[allow(clippy::result_unit_err)]
+            pub fn hit_or_unit(self) -> ::core::result::Result<T, ()> {
+                self.into_either()
+            }
+
+            #[inline]
+            pub fn filter_map_ref<U, F>(&self, f: F) -> Option<U>
+            where
+                F: FnOnce(&T) -> Option<U>,
+            {
+                match self {
+                    $some(x) => f(x),
+                    $none => None,
+                }
+            }
+
+            #[inline]
+            pub fn diff(&self, other: &Self) -> $crate::Diff
+            where
+                T: PartialEq,
+            {
+                match (self, other) {
+                    ($none, $none) => $crate::Diff::NoChange,
+                    ($none, $some(_)) => $crate::Diff::Added,
+                    ($some(_), $none) => $crate::Diff::Removed,
+                    ($some(a), $some(b)) if a == b => $crate::Diff::Same,
+                    ($some(_), $some(_)) => $crate::Diff::Changed,
+                }
+            }
+
+            #[inline]
+            pub fn retain_ref<P>(&mut self, predicate: P) -> bool
+            where
+                P: FnOnce(&T) -> bool,
+            {
+                let retained = match self {
+                    $some(x) => predicate(x),
+                    $none => false,
+                };
+                if !retained {
+                    *self = $none;
+                }
+                retained
+            }
+
+            #[inline]
+            pub fn zip_map_or<U, R, F>(self, other: $name<U>, default: R, f: F) -> R
+            where
+                F: FnOnce(T, U) -> R,
+            {
+                match (self, other) {
+                    ($some(a), $some(b)) => f(a, b),
+                    _ => default,
+                }
+            }
+
+            #[inline]
+            pub fn take_map_or<U, F>(&mut self, default: U, f: F) -> U
+            where
+                F: FnOnce(T) -> U,
+            {
+                match core::mem::replace(self, $none) {
+                    $some(x) => f(x),
+                    $none => default,
+                }
+            }
+        }
+
+        $crate::__option_like_int!($name, $some, $none; u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+        $crate::__option_like_alloc! {
+            impl<T> $name<T> {
+                pub fn ok_or_msg(self, context: &str) -> ::core::result::Result<T, $crate::__private::String> {
+                    match self {
+                        $some(x) => ::core::result::Result::Ok(x),
+                        $none => ::core::result::Result::Err($crate::__private::format!("{}: {} was {}", context, stringify!($name), stringify!($none))),
+                    }
+                }
+
+                pub fn collect_hit<I>(iter: I) -> $name<$crate::__private::Vec<T>>
+                where
+                    I: IntoIterator<Item = T>,
+                {
+                    let items: $crate::__private::Vec<T> = iter.into_iter().collect();
+                    if items.is_empty() { $none } else { $some(items) }
+                }
+
+                pub fn index_hits<I>(iter: I) -> $crate::__private::Vec<(usize, T)>
+                where
+                    I: IntoIterator<Item = Self>,
+                {
+                    iter.into_iter()
+                        .enumerate()
+                        .filter_map(|(index, item)| match item {
+                            $some(x) => Some((index, x)),
+                            $none => None,
+                        })
+                        .collect()
+                }
+
+                pub fn partition_map<A, B, F, I>(iter: I, mut f: F) -> ($crate::__private::Vec<A>, $crate::__private::Vec<B>)
+                where
+                    F: FnMut(T) -> $crate::Either<A, B>,
+                    I: IntoIterator<Item = Self>,
+                {
+                    let mut lefts = $crate::__private::Vec::new();
+                    let mut rights = $crate::__private::Vec::new();
+                    for value in iter.into_iter().filter_map(Option::<T>::from) {
+                        match f(value) {
+                            $crate::Either::Left(a) => lefts.push(a),
+                            $crate::Either::Right(b) => rights.push(b),
+                        }
+                    }
+                    (lefts, rights)
+                }
+
+                #[doc = concat!("Returns `", stringify!($some), "` with clones of all inner values if every item is `", stringify!($some), "`, otherwise returns `", stringify!($none), "`. An empty slice yields `", stringify!($some), "` with an empty vector.")]
+                pub fn zip_all(items: &[Self]) -> $name<$crate::__private::Vec<T>>
+                where
+                    T: Clone,
+                {
+                    items
+                        .iter()
+                        .map(|item| match item {
+                            $some(x) => Some(x.clone()),
+                            $none => None,
+                        })
+                        .collect::<Option<$crate::__private::Vec<T>>>()
+                        .into()
+                }
+
+                pub fn try_collect_hits<U, E, F, I>(iter: I, f: F) -> ::core::result::Result<$crate::__private::Vec<U>, E>
+                where
+                    F: FnMut(T) -> ::core::result::Result<U, E>,
+                    I: IntoIterator<Item = Self>,
+                {
+                    iter.into_iter().filter_map(Option::<T>::from).map(f).collect()
+                }
+
+                pub fn collect_hits_or_errors<U, E, F, I>(iter: I, mut f: F) -> ($crate::__private::Vec<U>, $crate::__private::Vec<E>)
+                where
+                    F: FnMut(T) -> ::core::result::Result<U, E>,
+                    I: IntoIterator<Item = Self>,
+                {
+                    let mut oks = $crate::__private::Vec::new();
+                    let mut errs = $crate::__private::Vec::new();
+                    for value in iter.into_iter().filter_map(Option::<T>::from) {
+                        match f(value) {
+                            ::core::result::Result::Ok(u) => oks.push(u),
+                            ::core::result::Result::Err(e) => errs.push(e),
+                        }
+                    }
+                    (oks, errs)
+                }
+            }
+
+            impl<T> $name<$crate::__private::Vec<T>> {
+                pub fn scan_inner<A, F>(self, init: A, mut f: F) -> $name<$crate::__private::Vec<A>>
+                where
+                    A: Clone,
+                    F: FnMut(&A, &T) -> A,
+                {
+                    match self {
+                        $some(items) => {
+                            let mut acc = init;
+                            let mut states = $crate::__private::Vec::with_capacity(items.len());
+                            for item in &items {
+                                acc = f(&acc, item);
+                                states.push(acc.clone());
+                            }
+                            $some(states)
+                        }
+                        $none => $none,
+                    }
+                }
+            }
+        }
+
+        $crate::__option_like_std! {
+            impl<T> $name<T> {
+                pub fn count_by<K, F, I>(iter: I, mut key: F) -> $crate::__private::HashMap<K, usize>
+                where
+                    K: Eq + core::hash::Hash,
+                    F: FnMut(&T) -> K,
+                    I: IntoIterator<Item = Self>,
+                {
+                    let mut counts = $crate::__private::HashMap::new();
+                    for value in iter.into_iter().filter_map(Option::<T>::from) {
+                        *counts.entry(key(&value)).or_insert(0) += 1;
+                    }
+                    counts
+                }
+
+                /// Prints the variant and the value to stderr and returns `self` unchanged. Intended for temporary debugging, so it is only available when `debug_assertions` are enabled.
+                #[cfg(debug_assertions)]
+                pub fn debug_tap(self) -> Self
+                where
+                    T: core::fmt::Debug,
+                {
+                    match &self {
+                        $some(x) => $crate::__private::eprintln!("{}({:?})", stringify!($some), x),
+                        $none => $crate::__private::eprintln!("{}", stringify!($none)),
+                    }
+                    self
+                }
+
+                pub fn timed_map<U, F>(self, f: F) -> ($name<U>, core::time::Duration)
+                where
+                    F: FnOnce(T) -> U,
+                {
+                    match self {
+                        $some(x) => {
+                            let start = $crate::__private::Instant::now();
+                            let value = f(x);
+                            ($some(value), start.elapsed())
+                        }
+                        $none => ($none, core::time::Duration::ZERO),
+                    }
+                }
+
+                pub fn insert_into<K, S>(self, map: &mut $crate::__private::HashMap<K, T, S>, key: K) -> bool
+                where
+                    K: Eq + core::hash::Hash,
+                    S: core::hash::BuildHasher,
+                {
+                    match self {
+                        $some(x) => {
+                            map.insert(key, x);
+                            true
+                        }
+                        $none => false,
+                    }
+                }
+            }
+        }
+
+        $crate::__option_like_heapless! {
+            impl<T> $name<T> {
+                pub fn collect_hits_heapless<const N: usize, I>(iter: I) -> $crate::__private::heapless::Vec<T, N>
+                where
+                    I: IntoIterator<Item = Self>,
+                {
+                    let mut hits = $crate::__private::heapless::Vec::new();
+                    for value in iter.into_iter().filter_map(Option::<T>::from) {
+                        if hits.push(value).is_err() {
+                            break;
+                        }
+                    }
+                    hits
+                }
+            }
+        }
+
+        impl<T> $crate::OptionLike for $name<T> {
+            type Value = T;
+
+            #[inline]
+            fn into_option(self) -> Option<T> {
+                self.into()
+            }
+
+            #[inline]
+            fn as_option(&self) -> Option<&T> {
+                match self {
+                    $some(x) => Some(x),
+                    $none => None,
+                }
+            }
+        }
+
+        impl<T> From<[Option<T>; 1]> for $name<T> {
+            fn from([value]: [Option<T>; 1]) -> Self {
+                value.into()
+            }
+        }
+
+        impl<T> From<$name<T>> for [Option<T>; 1] {
+            fn from(value: $name<T>) -> [Option<T>; 1] {
+                [value.into()]
+            }
+        }
+
+        impl<T> $name<$name<T>> {
+            #[inline]
+            pub fn flatten_self(self) -> $name<T> {
+                Option::<Option<T>>::from(self.map(Option::<T>::from)).flatten().into()
+            }
+        }
+
+        impl<T, E: Default> From<$name<T>> for ::core::result::Result<T, E> {
+            fn from(value: $name<T>) -> ::core::result::Result<T, E> {
+                match value {
+                    $some(inner) => ::core::result::Result::Ok(inner),
+                    $none => ::core::result::Result::Err(E::default()),
+                }
+            }
+        }
+
+        impl<T> From<Option<T>> for $name<T> {
+            fn from(value: Option<T>) -> Self {
+                match value {
+                    Some(inner) => $some(inner),
+                    None => $none
+                }
+            }
+        }
+
+        impl<T> From<$name<T>> for Option<T> {
+            fn from(value: $name<T>) -> Option<T> {
+                match value {
+                    $some(inner) => Some(inner),
+                    $none => None
+                }
+            }
+        }
+
+        #[cold]
+        #[track_caller]
+        const fn unwrap_failed() -> ! {
+            panic!(stringify!("called `", $name, "::unwrap()` on a `", $none, "` value"))
+        }
+
+        #[cold]
+        #[track_caller]
+        const fn expect_failed(msg: &str) -> ! {
+            panic!("{}", msg)
+        }
+
+        #[cold]
+        #[track_caller]
+        fn expect_display_failed(msg: impl core::fmt::Display) -> ! {
+            panic!("{}", msg)
+        }
+
+        $crate::__option_like_clauses!([$vis] $name, $some, $none; $($clauses)*);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __option_like_int {
+    ($name:ident, $some:ident, $none:ident; $($int:ty)*) => {
+        $(
+            impl $name<$int> {
+                #[inline]
+                pub const fn map_saturating_add(self, rhs: $int) -> Self {
+                    match self {
+                        $some(x) => $some(x.saturating_add(rhs)),
+                        $none => $none,
+                    }
+                }
+
+                #[inline]
+                pub const fn map_wrapping_add(self, rhs: $int) -> Self {
+                    match self {
+                        $some(x) => $some(x.wrapping_add(rhs)),
+                        $none => $none,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+/// Returns `true` if an [`OptionLike`] value holds a value that matches the pattern.
+///
+/// The pattern is matched against a reference to the inner value, so no variant names need to be imported.
+///
+/// # Example
+///
+/// ```
+/// use option_like::{is_hit_matching, option_like};
+///
+/// option_like!(
+///     enum Cached<T> {
+///         Hit(T),
+///         Miss,
+///     }
+///
+///     is_some => is_hit
+///     is_none => is_miss
+/// );
+///
+/// assert!(is_hit_matching!(Cached::Hit(42), 40..=50));
+/// assert!(!is_hit_matching!(Cached::<u32>::Miss, _));
+/// ```
+#[macro_export]
+macro_rules! is_hit_matching {
+    ($expr:expr, $pat:pat $(if $guard:expr)? $(,)?) => {
+        ::core::matches!($crate::OptionLike::as_option(&$expr), ::core::option::Option::Some($pat) $(if $guard)?)
+    };
+}
+
+/// Matches an [`OptionLike`] value without naming its variants.
+///
+/// # Example
+///
+/// ```
+/// use option_like::{option_like, option_like_match};
+///
+/// option_like!(
+///     enum Cached<T> {
+///         Hit(T),
+///         Miss,
+///     }
+///
+///     is_some => is_hit
+///     is_none => is_miss
+/// );
+///
+/// let value = option_like_match!(Cached::Hit(42), some(x) => x, none => 0);
+/// assert_eq!(value, 42);
+/// ```
+#[macro_export]
+macro_rules! option_like_match {
+    ($expr:expr, some($pat:pat) => $some:expr, none => $none:expr $(,)?) => {
+        match $crate::OptionLike::into_option($expr) {
+            ::core::option::Option::Some($pat) => $some,
+            ::core::option::Option::None => $none,
+        }
+    };
+}
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __option_like_alloc {
+    ($($tt:tt)*) => {
+        $($tt)*
+    };
+}
+
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __option_like_alloc {
+    ($($tt:tt)*) => {};
+}
+
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __option_like_std {
+    ($($tt:tt)*) => {
+        $($tt)*
+    };
+}
+
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __option_like_std {
+    ($($tt:tt)*) => {};
+}
+
+#[cfg(feature = "heapless")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __option_like_heapless {
+    ($($tt:tt)*) => {
+        $($tt)*
+    };
+}
+
+#[cfg(not(feature = "heapless"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __option_like_heapless {
+    ($($tt:tt)*) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __option_like_clauses {
+    ([$vis:vis] $name:ident, $some:ident, $none:ident;) => {};
+    ([$vis:vis] $name:ident, $some:ident, $none:ident; lazy => $lazy:ident $($rest:tt)*) => {
+        $vis struct $lazy<T, F> {
+            init: Option<F>,
+            value: Option<$name<T>>,
+        }
+
+        impl<T, F> $lazy<T, F>
+        where
+            F: FnOnce() -> Option<T>,
+        {
+            pub const fn new(init: F) -> Self {
+                Self {
+                    init: Some(init),
+                    value: None,
+                }
+            }
+
+            pub fn get(&mut self) -> &$name<T> {
+                let init = &mut self.init;
+                self.value.get_or_insert_with(|| match init.take() {
+                    Some(f) => f().into(),
+                    None => $none,
+                })
+            }
+
+            pub fn into_inner(mut self) -> $name<T> {
+                self.get();
+                self.value.unwrap_or($none)
+            }
+        }
+
+        $crate::__option_like_clauses!([$vis] $name, $some, $none; $($rest)*);
+    };
+    ([$vis:vis] $name:ident, $some:ident, $none:ident; ops $($rest:tt)*) => {
+        impl<T> core::ops::BitOr for $name<T> {
+            type Output = Self;
+
+            fn bitor(self, rhs: Self) -> Self {
+                self.or(rhs)
+            }
+        }
+
+        impl<T> core::ops::BitAnd for $name<T> {
+            type Output = Self;
+
+            fn bitand(self, rhs: Self) -> Self {
+                self.and(rhs)
+            }
+        }
+
+        $crate::__option_like_clauses!([$vis] $name, $some, $none; $($rest)*);
+    };
+    ([$vis:vis] $name:ident, $some:ident, $none:ident; double_buffer => $buffer:ident $($rest:tt)*) => {
+        $vis struct $buffer<T> {
+            front: $name<T>,
+            back: $name<T>,
+        }
+
+        impl<T> $buffer<T> {
+            pub const fn new(front: $name<T>, back: $name<T>) -> Self {
+                Self {
+                    front,
+                    back,
+                }
+            }
+
+            pub const fn front(&self) -> &$name<T> {
+                &self.front
+            }
+
+            pub const fn back_mut(&mut self) -> &mut $name<T> {
+                &mut self.back
+            }
+
+            pub fn swap_and_take(&mut self) -> $name<T> {
+                core::mem::swap(&mut self.front, &mut self.back);
+                core::mem::replace(&mut self.back, $none)
+            }
+        }
+
+        $crate::__option_like_clauses!([$vis] $name, $some, $none; $($rest)*);
+    };
+    ([$vis:vis] $name:ident, $some:ident, $none:ident; into => $wrapper:ident $($rest:tt)*) => {
+        impl<T> From<$name<T>> for $wrapper<T> {
+            fn from(value: $name<T>) -> $wrapper<T> {
+                $wrapper::from(Option::<T>::from(value))
+            }
+        }
+
+        $crate::__option_like_clauses!([$vis] $name, $some, $none; $($rest)*);
+    };
+    ([$vis:vis] $name:ident, $some:ident, $none:ident; eq_inner $($rest:tt)*) => {
+        impl<T: PartialEq> PartialEq<T> for $name<T> {
+            fn eq(&self, other: &T) -> bool {
+                match self {
+                    $some(x) => x == other,
+                    $none => false,
+                }
+            }
+        }
+
+        $crate::__option_like_clauses!([$vis] $name, $some, $none; $($rest)*);
+    };
+    ([$vis:vis] $name:ident, $some:ident, $none:ident; ring => $ring:ident $($rest:tt)*) => {
+        $vis struct $ring<T, const N: usize> {
+            slots: [$name<T>; N],
+            next: usize,
+        }
+
+        impl<T, const N: usize> $ring<T, N> {
+            pub const fn new() -> Self {
+                Self {
+                    slots: [const { $none }; N],
+                    next: 0,
+                }
+            }
+
+            pub const fn slots(&self) -> &[$name<T>; N] {
+                &self.slots
+            }
+
+            pub fn push(&mut self, value: T) -> $name<T> {
+                match self.slots.get_mut(self.next) {
+                    Some(slot) => {
+                        let evicted = core::mem::replace(slot, $some(value));
+                        self.next = (self.next + 1) % N;
+                        evicted
+                    }
+                    None => $some(value),
+                }
+            }
+        }
+
+        impl<T, const N: usize> Default for $ring<T, N> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        $crate::__option_like_clauses!([$vis] $name, $some, $none; $($rest)*);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    option_like!(
+        #[derive(Ord, PartialOrd, Eq, PartialEq, Default, Clone, Debug)]
+        enum Cached<T> {
+            Hit(T),
+            #[default]
+            Miss,
+        }
+
+        is_some => is_hit
+        is_none => is_miss
+    );
+
+    fn hit() -> Cached<bool> {
+        Hit(true)
+    }
+
+    fn miss() -> Cached<bool> {
+        Miss
+    }
+
+    #[test]
+    fn test_boolean_methods() {
+        assert!(hit().is_hit());
+        assert!(miss().is_miss());
+    }
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Option::<bool>::from(hit()), Some(true));
+        assert_eq!(Option::<bool>::from(miss()), None);
+        assert_eq!(Cached::<bool>::from(Some(true)), Hit(true));
+        assert_eq!(Cached::<bool>::from(None), Miss);
+    }
+
+    #[test]
+    fn test_map() {
+        assert_eq!(hit().map(|t| !t), Hit(false));
+        assert_eq!(miss().map(|t| !t), Miss);
+    }
+
+    #[test]
+    fn test_unwrap_or_default() {
+        assert!(hit().unwrap_or_default());
+        assert!(!miss().unwrap_or_default());
+    }
+
+    #[test]
+    fn test_unwrap_or_else() {
+        assert!(hit().unwrap_or_else(|| false));
+        assert!(miss().unwrap_or_else(|| true));
+    }
+
+    #[test]
+    fn test_unwrap_no_panic() {
+        assert!(hit().unwrap());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unwrap_panic() {
+        miss().unwrap();
+    }
+
+    #[test]
+    fn test_expect_no_panic() {
+        assert!(hit().expect("should not panic"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_expect_panic() {
+        miss().expect("should panic");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_ok_or_msg() {
+        assert_eq!(hit().ok_or_msg("lookup"), Ok(true));
+        assert_eq!(miss().ok_or_msg("lookup").unwrap_err(), "lookup: Cached was Miss");
+    }
+
+    #[test]
+    fn test_filter_then_map() {
+        assert_eq!(Hit(2).filter_then_map(|x| x % 2 == 0, |x| x * 10), Hit(20));
+        assert_eq!(Hit(3).filter_then_map(|x| x % 2 == 0, |x| x * 10), Miss);
+        assert_eq!(Cached::<u32>::Miss.filter_then_map(|_| unreachable!(), |_: u32| -> u32 { unreachable!() }), Miss);
+    }
+
+    #[test]
+    fn test_as_option_deref() {
+        extern crate alloc;
+        use alloc::string::{String, ToString};
+        assert_eq!(Hit("name".to_string()).as_option_deref(), Some("name"));
+        assert_eq!(Cached::<String>::Miss.as_option_deref(), None);
+    }
+
+    #[test]
+    fn test_and() {
+        assert_eq!(hit().and(Hit(1)), Hit(1));
+        assert_eq!(hit().and(Cached::<u32>::Miss), Miss);
+        assert_eq!(miss().and(Hit(1)), Miss);
+    }
+
+    #[test]
+    fn test_or() {
+        assert_eq!(hit().or(Hit(false)), Hit(true));
+        assert_eq!(hit().or(miss()), Hit(true));
+        assert_eq!(miss().or(Hit(false)), Hit(false));
+        assert_eq!(miss().or(miss()), Miss);
+    }
+
+    #[test]
+    fn test_sum_hits() {
+        assert_eq!(Cached::sum_hits([Hit(1u32), Miss, Hit(2), Miss, Hit(3)]), 6);
+        assert_eq!(Cached::<u32>::sum_hits([Miss, Miss]), 0);
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(Cached::try_new(4, |x| x % 2 == 0), Hit(4));
+        assert_eq!(Cached::try_new(5, |x| x % 2 == 0), Miss);
+    }
+
+    #[test]
+    fn test_and_then_result() {
+        assert_eq!(Hit(2).and_then_result(|x| Ok::<_, ()>(Hit(x * 2))), Ok(Hit(4)));
+        assert_eq!(Hit(2).and_then_result(|_| Ok::<Cached<u32>, ()>(Miss)), Ok(Miss));
+        assert_eq!(Hit(2).and_then_result(|_| Err::<Cached<u32>, _>("failed")), Err("failed"));
+        assert_eq!(Cached::<u32>::Miss.and_then_result(|_| -> Result<Cached<u32>, ()> { unreachable!() }), Ok(Miss));
+    }
+
+    #[test]
+    fn test_labeled() {
+        assert_eq!(hit().labeled(), ("Hit", Some(&true)));
+        assert_eq!(miss().labeled(), ("Miss", None));
+    }
+
+    #[test]
+    fn test_expect_display_no_panic() {
+        assert!(hit().expect_display(format_args!("should not panic")));
+    }
+
+    #[test]
+    #[should_panic(expected = "lookup 42 failed")]
+    fn test_expect_display_panic() {
+        struct Lookup(u32);
+
+        impl core::fmt::Display for Lookup {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "lookup {} failed", self.0)
+            }
+        }
+
+        miss().expect_display(Lookup(42));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic(expected = "missing key: id")]
+    fn test_expect_display_panic_string() {
+        miss().expect_display(alloc::format!("missing key: {}", "id"));
+    }
+
+    #[test]
+    fn test_fold_hits() {
+        assert_eq!(Cached::fold_hits([Hit(1u32), Miss, Hit(2), Hit(3)], 10, |acc, x| acc + x), 16);
+        assert_eq!(Cached::<u32>::fold_hits([Miss, Miss], 10, |_, _| unreachable!()), 10);
+    }
+
+    #[test]
+    fn test_replace_if() {
+        let mut cached = Hit(1);
+        assert!(cached.replace_if(|x| *x == 1, 2));
+        assert_eq!(cached, Hit(2));
+        assert!(!cached.replace_if(|x| *x == 1, 3));
+        assert_eq!(cached, Hit(2));
+        let mut cached = Miss;
+        assert!(!cached.replace_if(|_| unreachable!(), 4));
+        assert_eq!(cached, Hit(4));
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        assert!(Hit(1.0).approx_eq(&Hit(1.05), 0.1));
+        assert!(Hit(1.05).approx_eq(&Hit(1.0), 0.1));
+        assert!(!Hit(1.0).approx_eq(&Hit(2.0), 0.1));
+        assert!(!Hit(2.0).approx_eq(&Hit(1.0), 0.1));
+        assert!(Cached::<f64>::Miss.approx_eq(&Miss, 0.1));
+        assert!(!Hit(1.0).approx_eq(&Miss, 0.1));
+        assert!(!Miss.approx_eq(&Hit(1.0), 0.1));
+    }
+
+    #[test]
+    fn test_approx_eq_extreme() {
+        assert!(!Hit(i32::MIN).approx_eq(&Hit(-1), 1));
+        assert!(!Hit(-1).approx_eq(&Hit(i32::MIN), 1));
+        assert!(Hit(i32::MIN).approx_eq(&Hit(i32::MIN), 0));
+        assert!(Hit(i32::MAX).approx_eq(&Hit(i32::MAX - 1), 1));
+        assert!(Hit(i32::MAX - 1).approx_eq(&Hit(i32::MAX), 1));
+        assert!(Hit(0u32).approx_eq(&Hit(1), 1));
+        assert!(!Hit(0u32).approx_eq(&Hit(2), 1));
+    }
+
+    #[test]
+    fn test_map_to_option() {
+        assert_eq!(hit().map_to_option(|t| !t), Some(false));
+        assert_eq!(miss().map_to_option(|_| -> bool { unreachable!() }), None);
+    }
+
+    #[test]
+    fn test_with_inner() {
+        let cached = Hit([1, 2, 3]);
+        assert_eq!(cached.with_inner(|x| x.len()), Some(3));
+        assert_eq!(cached, Hit([1, 2, 3]));
+        assert_eq!(Cached::<[u32; 3]>::Miss.with_inner(|x| x.len()), None);
+    }
+
+    #[test]
+    fn test_ok_or_else_log() {
+        let mut misses = 0;
+        assert_eq!(hit().ok_or_else_log(|| misses += 1, || -> () { unreachable!() }), Ok(true));
+        assert_eq!(misses, 0);
+        assert_eq!(miss().ok_or_else_log(|| misses += 1, || "missing"), Err("missing"));
+        assert_eq!(misses, 1);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_collect_hit() {
+        assert_eq!(Cached::collect_hit([1, 2, 3]), Hit(alloc::vec![1, 2, 3]));
+        assert_eq!(Cached::<u32>::collect_hit([]), Miss);
+    }
+
+    #[test]
+    fn test_is_hit_matching() {
+        assert!(is_hit_matching!(Hit(42), 42));
+        assert!(is_hit_matching!(Hit(42), 40..=50));
+        assert!(is_hit_matching!(Hit(42), x if *x > 40));
+        assert!(!is_hit_matching!(Hit(42), 0));
+        assert!(!is_hit_matching!(Cached::<u32>::Miss, _));
+    }
+
+    #[test]
+    fn test_drain_once() {
+        let mut cached = Hit(1);
+        let mut drain = cached.drain_once();
+        assert_eq!(cached, Miss);
+        assert_eq!(drain.next(), Some(1));
+        assert_eq!(drain.next(), None);
+        assert_eq!(cached.drain_once().next(), None);
+    }
+
+    #[test]
+    fn test_entry_mut() {
+        let mut cached = Hit(1);
+        let (inserted, value) = cached.entry_mut();
+        assert!(!inserted);
+        assert_eq!(*value, 1);
+        *value += 1;
+        assert_eq!(cached, Hit(2));
+        let mut cached = Cached::<u32>::Miss;
+        let (inserted, value) = cached.entry_mut();
+        assert!(inserted);
+        assert_eq!(*value, 0);
+        *value += 5;
+        assert_eq!(cached, Hit(5));
+    }
+
+    #[test]
+    fn test_and_then_map() {
+        assert_eq!(Hit(2).and_then_map(|x| Hit(x + 1), |x| x * 10), Hit(30));
+        assert_eq!(Hit(2).and_then_map(|_| Cached::<u32>::Miss, |_| -> u32 { unreachable!() }), Miss);
+        assert_eq!(Cached::<u32>::Miss.and_then_map(|_| -> Cached<u32> { unreachable!() }, |_| -> u32 { unreachable!() }), Miss);
+    }
+
+    #[test]
+    fn test_from_option_array() {
+        assert_eq!(<[Option<bool>; 1]>::from(hit()), [Some(true)]);
+        assert_eq!(<[Option<bool>; 1]>::from(miss()), [None]);
+        assert_eq!(Cached::<bool>::from([Some(true)]), hit());
+        assert_eq!(Cached::<bool>::from([None]), miss());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_index_hits() {
+        assert_eq!(Cached::index_hits([Miss, Hit('a'), Miss, Miss, Hit('b')]), alloc::vec![(1, 'a'), (4, 'b')]);
+        assert_eq!(Cached::<char>::index_hits([Miss, Miss]), alloc::vec![]);
+    }
+
+    #[test]
+    fn test_iterator_ext() {
+        use crate::OptionLikeIteratorExt;
+        let items = [Hit(1), Miss, Hit(2), Miss, Hit(3)];
+        assert!(items.clone().into_iter().hits().eq([1, 2, 3]));
+        assert!(items.into_iter().map_hits(|x| x * 10).eq([10, 20, 30]));
+        assert!([Some(1), None].into_iter().hits().eq([1]));
+    }
+
+    #[test]
+    fn test_option_like_match() {
+        let describe = |cached: Cached<bool>| option_like_match!(cached, some(value) => value, none => false);
+        assert!(describe(hit()));
+        assert!(!describe(miss()));
+        assert_eq!(option_like_match!(Some(1), some(x) => x + 1, none => 0), 2);
+    }
+
+    #[test]
+    fn test_zip_or() {
+        assert_eq!(Hit(1).zip_or(Hit('a'), "missing"), Ok((1, 'a')));
+        assert_eq!(Hit(1).zip_or(Cached::<char>::Miss, "missing"), Err("missing"));
+        assert_eq!(Cached::<u32>::Miss.zip_or(Hit('a'), "missing"), Err("missing"));
+    }
+
+    #[test]
+    fn test_zip_or_else() {
+        assert_eq!(Hit(1).zip_or_else(Hit('a'), || -> () { unreachable!() }), Ok((1, 'a')));
+        assert_eq!(Hit(1).zip_or_else(Cached::<char>::Miss, || "missing"), Err("missing"));
+        assert_eq!(Cached::<u32>::Miss.zip_or_else(Hit('a'), || "missing"), Err("missing"));
+    }
+
+    #[test]
+    fn test_map_or_insert() {
+        let mut cached = Hit(1);
+        cached.map_or_insert(0, |x| x + 1);
+        assert!(cached.is_hit());
+        assert_eq!(cached, Hit(2));
+        let mut cached = Miss;
+        cached.map_or_insert(0, |_| unreachable!());
+        assert!(cached.is_hit());
+        assert_eq!(cached, Hit(0));
+    }
+
+    #[test]
+    fn test_debug_miss_only() {
+        extern crate std;
+        use std::format;
+        struct NotDebug;
+        assert_eq!(format!("{:?}", Hit(NotDebug).debug_miss_only()), "Hit(..)");
+        assert_eq!(format!("{:?}", Cached::<NotDebug>::Miss.debug_miss_only()), "Miss");
+    }
+
+    #[test]
+    fn test_continue_on_hit() {
+        use core::ops::ControlFlow;
+        assert_eq!(hit().continue_on_hit(), ControlFlow::Continue(true));
+        assert_eq!(miss().continue_on_hit(), ControlFlow::Break(()));
+    }
+
+    #[test]
+    fn test_break_on_hit() {
+        use core::ops::ControlFlow;
+        assert_eq!(hit().break_on_hit(), ControlFlow::Break(true));
+        assert_eq!(miss().break_on_hit(), ControlFlow::Continue(()));
+    }
+
+    #[test]
+    fn test_get_or_default() {
+        let cached = hit();
+        assert!(cached.get_or_default());
+        assert_eq!(cached, Hit(true));
+        assert!(!miss().get_or_default());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_partition_map() {
+        use crate::Either::*;
+        let (evens, odds) = Cached::partition_map([Hit(1), Miss, Hit(2), Hit(3), Miss, Hit(4)], |x| if x % 2 == 0 { Left(x) } else { Right(x) });
+        assert_eq!(evens, alloc::vec![2, 4]);
+        assert_eq!(odds, alloc::vec![1, 3]);
+    }
+
+    #[test]
+    fn test_as_ref_ok_or() {
+        assert_eq!(hit().as_ref_ok_or("missing"), Ok(&true));
+        assert_eq!(miss().as_ref_ok_or("missing"), Err("missing"));
+    }
+
+    #[test]
+    fn test_as_ref_ok_or_else() {
+        assert_eq!(hit().as_ref_ok_or_else(|| -> () { unreachable!() }), Ok(&true));
+        assert_eq!(miss().as_ref_ok_or_else(|| "missing"), Err("missing"));
+    }
+
+    #[test]
+    fn test_map_panic_in_closure() {
+        extern crate std;
+        let result = std::panic::catch_unwind(|| hit().map(|_| -> bool { panic!("closure panicked") }));
+        let payload = result.unwrap_err();
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"closure panicked"));
+        assert_eq!(miss().map(|_| -> bool { panic!("closure panicked") }), Miss);
+    }
+
+    #[test]
+    fn test_update() {
+        assert_eq!(Hit(1).update(|x| x + 1), Hit(2));
+        assert_eq!(Cached::<u32>::Miss.update(|_| unreachable!()), Miss);
+    }
+
+    #[test]
+    fn test_variant_name() {
+        assert_eq!(hit().variant_name(), "Hit");
+        assert_eq!(miss().variant_name(), "Miss");
+    }
+
+    #[test]
+    fn test_from_variant_name() {
+        assert_eq!(Cached::<bool>::from_variant_name(hit().variant_name()), Some(Hit(false)));
+        assert_eq!(Cached::<bool>::from_variant_name(miss().variant_name()), Some(Miss));
+        assert_eq!(Cached::<bool>::from_variant_name("hit"), None);
+        assert_eq!(Cached::<bool>::from_variant_name(""), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_zip_all() {
+        assert_eq!(Cached::zip_all(&[Hit(1), Hit(2), Hit(3)]), Hit(alloc::vec![1, 2, 3]));
+        assert_eq!(Cached::zip_all(&[Hit(1), Miss, Hit(3)]), Miss);
+        assert_eq!(Cached::<u32>::zip_all(&[]), Hit(alloc::vec![]));
+    }
+
+    #[test]
+    fn test_observe() {
+        let mut observed = None;
+        assert_eq!(hit().observe(|is_hit| observed = Some(is_hit)), Hit(true));
+        assert_eq!(observed, Some(true));
+        assert_eq!(miss().observe(|is_hit| observed = Some(is_hit)), Miss);
+        assert_eq!(observed, Some(false));
+    }
+
+    #[test]
+    fn test_reduce_hits() {
+        use crate::OptionLikeIteratorExt;
+        let mut calls = 0;
+        let max = [Hit(3), Miss, Hit(7), Miss, Hit(5)]
+            .into_iter()
+            .reduce_hits(|a, b| {
+                calls += 1;
+                a.max(b)
+            });
+        assert_eq!(max, Some(7));
+        assert_eq!(calls, 2);
+        assert_eq!(
+            [Cached::<u32>::Miss, Miss]
+                .into_iter()
+                .reduce_hits(|_, _| unreachable!()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_map_saturating_add() {
+        macro_rules! check {
+            ($($int:ty)*) => {
+                $(
+                    assert_eq!(Hit(<$int>::MAX - 1).map_saturating_add(2), Hit(<$int>::MAX));
+                    assert_eq!(Hit::<$int>(1).map_saturating_add(2), Hit(3));
+                    assert_eq!(Cached::<$int>::Miss.map_saturating_add(2), Miss);
+                )*
+            };
+        }
+        check!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+    }
+
+    #[test]
+    fn test_map_wrapping_add() {
+        macro_rules! check {
+            ($($int:ty)*) => {
+                $(
+                    assert_eq!(Hit(<$int>::MAX - 1).map_wrapping_add(2), Hit(<$int>::MIN));
+                    assert_eq!(Hit::<$int>(1).map_wrapping_add(2), Hit(3));
+                    assert_eq!(Cached::<$int>::Miss.map_wrapping_add(2), Miss);
+                )*
+            };
+        }
+        check!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+    }
+
+    #[test]
+    fn test_into_std_option() {
+        assert_eq!(hit().into_std_option(), Some(true));
+        assert_eq!(miss().into_std_option(), None);
+    }
+
+    #[test]
+    fn test_map_from_std() {
+        assert_eq!(Cached::map_from_std(Some(true)), hit());
+        assert_eq!(Cached::<bool>::map_from_std(None), miss());
+    }
+
+    #[test]
+    fn test_niche_optimization() {
+        use core::mem::size_of;
+        use core::num::NonZeroU32;
+        assert_eq!(size_of::<Cached<NonZeroU32>>(), size_of::<Option<NonZeroU32>>());
+        assert_eq!(size_of::<Cached<NonZeroU32>>(), size_of::<NonZeroU32>());
+        assert_eq!(size_of::<Cached<&u64>>(), size_of::<&u64>());
+    }
+
+    #[test]
+    fn test_compare_and_set() {
+        let mut cached = Hit(1);
+        assert!(cached.compare_and_set(&1, 2));
+        assert_eq!(cached, Hit(2));
+        assert!(!cached.compare_and_set(&1, 3));
+        assert_eq!(cached, Hit(2));
+        let mut cached = Miss;
+        assert!(!cached.compare_and_set(&1, 3));
+        assert_eq!(cached, Miss);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_count_by() {
+        use std::string::{String, ToString};
+        let items: [Cached<String>; 5] = [
+            Hit("apple".to_string()),
+            Miss,
+            Hit("avocado".to_string()),
+            Hit("banana".to_string()),
+            Miss,
+        ];
+        let counts = Cached::count_by(items, |s| s.chars().next());
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[&Some('a')], 2);
+        assert_eq!(counts[&Some('b')], 1);
+    }
+
+    #[test]
+    fn test_expect_ref_no_panic() {
+        let cached = hit();
+        assert_eq!(cached.expect_ref("should not panic"), &true);
+        assert_eq!(cached, Hit(true));
+    }
+
+    #[test]
+    #[should_panic(expected = "invariant violated")]
+    fn test_expect_ref_panic() {
+        miss().expect_ref("invariant violated");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_try_collect_hits() {
+        assert_eq!(Cached::try_collect_hits([Hit(1), Miss, Hit(2)], |x| Ok::<_, ()>(x * 10)), Ok(alloc::vec![10, 20]));
+        let mut calls = 0;
+        let result = Cached::try_collect_hits([Hit(1), Hit(2), Miss, Hit(3)], |x| {
+            calls += 1;
+            if x == 2 { Err(x) } else { Ok(x) }
+        });
+        assert_eq!(result, Err(2));
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn test_cloned_option() {
+        let cached = hit();
+        assert_eq!(cached.cloned_option(), Some(true));
+        assert_eq!(cached, Hit(true));
+        assert_eq!(miss().cloned_option(), None);
+    }
+
+    #[cfg(all(feature = "std", debug_assertions))]
+    #[test]
+    fn test_debug_tap() {
+        assert_eq!(hit().debug_tap(), Hit(true));
+        assert_eq!(miss().debug_tap(), Miss);
+    }
+
+    #[test]
+    fn test_repeat_none() {
+        const MISSES: [Cached<u32>; 8] = Cached::repeat_none();
+        assert!(MISSES.iter().all(Cached::is_miss));
+        assert_eq!(Cached::<u32>::repeat_none::<0>(), []);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_collect_hits_or_errors() {
+        let (oks, errs) = Cached::collect_hits_or_errors([Hit(1), Miss, Hit(2), Hit(3), Miss, Hit(4)], |x| if x % 2 == 0 { Ok(x * 10) } else { Err(x) });
+        assert_eq!(oks, alloc::vec![20, 40]);
+        assert_eq!(errs, alloc::vec![1, 3]);
+    }
+
+    #[test]
+    fn test_set_if_none() {
+        let mut cached = Miss;
+        assert!(cached.set_if_none(1));
+        assert_eq!(cached, Hit(1));
+        assert!(!cached.set_if_none(2));
+        assert_eq!(cached, Hit(1));
+    }
+
+    #[test]
+    fn test_first_last() {
+        let cached = hit();
+        assert_eq!(cached.first(), Some(&true));
+        assert_eq!(cached.last(), Some(&true));
+        assert!(core::ptr::eq(cached.first().unwrap(), cached.last().unwrap()));
+        assert_eq!(miss().first(), None);
+        assert_eq!(miss().last(), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_timed_map() {
+        let (cached, elapsed) = Hit(2).timed_map(|x| x * 10);
+        assert_eq!(cached, Hit(20));
+        assert!(elapsed >= core::time::Duration::ZERO);
+        let (cached, elapsed) = Cached::<u32>::Miss.timed_map(|_| -> u32 { unreachable!() });
+        assert_eq!(cached, Miss);
+        assert_eq!(elapsed, core::time::Duration::ZERO);
+    }
+
+    #[test]
+    fn test_from_array() {
+        assert_eq!(Cached::from_array([1]), Hit(1));
+    }
+
+    #[test]
+    fn test_from_slice_first() {
+        assert_eq!(Cached::from_slice_first(&[1, 2, 3]), Hit(1));
+        assert_eq!(Cached::<u32>::from_slice_first(&[]), Miss);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_scan_inner() {
+        assert_eq!(Hit(alloc::vec![1, 2, 3, 4]).scan_inner(0, |acc, x| acc + x), Hit(alloc::vec![1, 3, 6, 10]));
+        assert_eq!(Cached::<alloc::vec::Vec<u32>>::Miss.scan_inner(0, |_, _| unreachable!()), Miss);
+    }
+
+    #[test]
+    fn test_unwrap_or_else_reason() {
+        assert!(hit().unwrap_or_else_reason(|_| unreachable!()));
+        assert!(miss().unwrap_or_else_reason(|()| true));
+    }
+
+    #[test]
+    fn test_flatten_self() {
+        assert_eq!(Hit(hit()).flatten_self(), Hit(true));
+        assert_eq!(Hit(miss()).flatten_self(), Miss);
+        assert_eq!(Cached::<Cached<bool>>::Miss.flatten_self(), Miss);
+    }
+
+    #[test]
+    fn test_take_map() {
+        let mut cached = Hit(2);
+        assert_eq!(cached.take_map(|x| x * 10), Hit(20));
+        assert_eq!(cached, Miss);
+        assert_eq!(cached.take_map(|_| -> u32 { unreachable!() }), Miss);
+        assert_eq!(cached, Miss);
+    }
+
+    #[test]
+    fn test_cmp_with_none_policy() {
+        use core::cmp::Ordering::*;
+        for none_is in [Less, Equal, Greater] {
+            assert_eq!(Cached::<u32>::Miss.cmp_with_none_policy(&Hit(1), none_is), none_is);
+            assert_eq!(Hit(1).cmp_with_none_policy(&Miss, none_is), none_is.reverse());
+            assert_eq!(Cached::<u32>::Miss.cmp_with_none_policy(&Miss, none_is), Equal);
+            assert_eq!(Hit(1).cmp_with_none_policy(&Hit(2), none_is), Less);
+            assert_eq!(Hit(2).cmp_with_none_policy(&Hit(1), none_is), Greater);
+            assert_eq!(Hit(1).cmp_with_none_policy(&Hit(1), none_is), Equal);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_insert_into() {
+        let mut map = std::collections::HashMap::new();
+        assert!(Hit(1).insert_into(&mut map, "a"));
+        assert!(!Miss.insert_into(&mut map, "b"));
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get("a"), Some(&1));
+        let mut map = std::collections::HashMap::with_hasher(core::hash::BuildHasherDefault::<std::collections::hash_map::DefaultHasher>::default());
+        assert!(Hit(2).insert_into(&mut map, "c"));
+        assert_eq!(map.get("c"), Some(&2));
+    }
+
+    #[test]
+    fn test_first_some() {
+        let mut visited = 0;
+        let sources = [None, Some(1), Some(2)]
+            .into_iter()
+            .inspect(|_| visited += 1);
+        assert_eq!(Cached::first_some(sources), Hit(1));
+        assert_eq!(visited, 2);
+        assert_eq!(Cached::<u32>::first_some([None, None]), Miss);
+    }
+
+    #[test]
+    fn test_as_mut_map() {
+        extern crate alloc;
+        use alloc::vec::Vec;
+        let mut cached = Hit(alloc::vec![1u8, 2]);
+        assert_eq!(
+            cached.as_mut_map(|x| {
+                x.push(3);
+                x.len()
+            }),
+            Some(3)
+        );
+        assert_eq!(cached, Hit(alloc::vec![1, 2, 3]));
+        let mut cached = Cached::<Vec<u8>>::Miss;
+        assert_eq!(cached.as_mut_map(|_| -> usize { unreachable!() }), None);
+        assert_eq!(cached, Miss);
+    }
+
+    #[test]
+    fn test_into_either() {
+        assert_eq!(hit().into_either(), Ok(true));
+        assert_eq!(miss().into_either(), Err(()));
+        assert_eq!(hit().hit_or_unit(), Ok(true));
+        assert_eq!(miss().hit_or_unit(), Err(()));
+        let negate = |cached: Cached<bool>| -> Result<bool, ()> { Ok(!cached.into_either()?) };
+        assert_eq!(negate(hit()), Ok(false));
+        assert_eq!(negate(miss()), Err(()));
+    }
+
+    #[test]
+    fn test_filter_map_ref() {
+        let cached = Hit(4u32);
+        assert_eq!(cached.filter_map_ref(|x| x.checked_sub(1)), Some(3));
+        assert_eq!(cached.filter_map_ref(|x| x.checked_sub(5)), None);
+        assert_eq!(cached, Hit(4));
+        assert_eq!(Cached::<u32>::Miss.filter_map_ref(|_| -> Option<u32> { unreachable!() }), None);
+    }
+
+    #[test]
+    fn test_diff() {
+        use crate::Diff::*;
+        assert_eq!(miss().diff(&miss()), NoChange);
+        assert_eq!(miss().diff(&hit()), Added);
+        assert_eq!(hit().diff(&miss()), Removed);
+        assert_eq!(hit().diff(&hit()), Same);
+        assert_eq!(hit().diff(&Hit(false)), Changed);
+    }
+
+    #[test]
+    fn test_retain_ref() {
+        let mut cached = Hit(2);
+        assert!(cached.retain_ref(|x| x % 2 == 0));
+        assert_eq!(cached, Hit(2));
+        assert!(!cached.retain_ref(|x| x % 2 == 1));
+        assert_eq!(cached, Miss);
+        assert!(!cached.retain_ref(|_| unreachable!()));
+        assert_eq!(cached, Miss);
+    }
+
+    #[test]
+    fn test_into_result() {
+        #[derive(Eq, PartialEq, Default, Debug)]
+        struct MissError(u32);
+
+        assert_eq!(Result::<bool, MissError>::from(hit()), Ok(true));
+        assert_eq!(Result::<bool, MissError>::from(miss()), Err(MissError(0)));
+    }
+
+    #[test]
+    fn test_zip_map_or() {
+        assert_eq!(Hit(2).zip_map_or(Hit(3), 0, |a, b| a * b), 6);
+        assert_eq!(Hit(2).zip_map_or(Miss, 0, |_, _: u32| unreachable!()), 0);
+        assert_eq!(Cached::<u32>::Miss.zip_map_or(Hit(3), 0, |_, _| unreachable!()), 0);
+        assert_eq!(Cached::<u32>::Miss.zip_map_or(Cached::<u32>::Miss, 0, |_, _| unreachable!()), 0);
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn test_collect_hits_heapless() {
+        let hits = Cached::collect_hits_heapless::<4, _>([
+            Hit(1u32),
+            Miss,
+            Hit(2),
+            Hit(3),
+            Miss,
+            Hit(4),
+            Hit(5),
+            Hit(6),
+        ]);
+        assert_eq!(hits.as_slice(), &[1, 2, 3, 4]);
+        let hits = Cached::<u32>::collect_hits_heapless::<4, _>([Miss, Hit(1)]);
+        assert_eq!(hits.as_slice(), &[1]);
+    }
+
+    #[test]
+    fn test_take_map_or() {
+        let mut cached = Hit(2);
+        assert_eq!(cached.take_map_or(0, |x| x * 10), 20);
+        assert_eq!(cached, Miss);
+        assert_eq!(cached.take_map_or(0, |_| unreachable!()), 0);
+        assert_eq!(cached, Miss);
+    }
+
+    #[allow(dead_code)]
+    mod clauses {
+        use core::cell::Cell;
+
+        #[derive(Eq, PartialEq, Debug)]
+        struct MyWrapper<T>(Option<T>);
+
+        impl<T> From<Option<T>> for MyWrapper<T> {
+            fn from(value: Option<T>) -> Self {
+                Self(value)
+            }
+        }
+
+        option_like!(
+            #[derive(Eq, PartialEq, Debug)]
+            enum Cached<T> {
+                Hit(T),
+                Miss,
+            }
+
+            is_some => is_hit
+            is_none => is_miss
+
+            lazy => LazyCached
+            ops
+            double_buffer => DoubleBuffered
+            into => MyWrapper
+            eq_inner
+            ring => RingSlot
+        );
+
+        #[test]
+        fn test_lazy_get() {
+            let calls = Cell::new(0);
+            let mut lazy = LazyCached::new(|| {
+                calls.set(calls.get() + 1);
+                Some(42)
+            });
+            assert_eq!(calls.get(), 0);
+            assert_eq!(lazy.get(), &Hit(42));
+            assert_eq!(lazy.get(), &Hit(42));
+            assert_eq!(calls.get(), 1);
+        }
+
+        #[test]
+        fn test_lazy_get_none() {
+            let mut lazy = LazyCached::new(|| None::<u32>);
+            assert_eq!(lazy.get(), &Miss);
+            assert_eq!(lazy.into_inner(), Miss);
+        }
+
+        #[test]
+        fn test_bitor() {
+            assert_eq!(Hit(1) | Miss, Hit(1));
+            assert_eq!(Miss | Hit(2), Hit(2));
+            assert_eq!(Hit(1) | Hit(2), Hit(1));
+            assert_eq!(Cached::<u32>::Miss | Miss, Miss);
+        }
+
+        #[test]
+        fn test_bitand() {
+            assert_eq!(Hit(1) & Miss, Miss);
+            assert_eq!(Miss & Hit(2), Miss);
+            assert_eq!(Hit(1) & Hit(2), Hit(2));
+        }
+
+        #[test]
+        fn test_swap_and_take() {
+            let mut buffer = DoubleBuffered::new(Hit(0), Miss);
+            *buffer.back_mut() = Hit(1);
+            assert_eq!(buffer.swap_and_take(), Hit(0));
+            assert_eq!(buffer.front(), &Hit(1));
+            *buffer.back_mut() = Hit(2);
+            assert_eq!(buffer.swap_and_take(), Hit(1));
+            assert_eq!(buffer.front(), &Hit(2));
+            assert_eq!(buffer.swap_and_take(), Hit(2));
+            assert_eq!(buffer.front(), &Miss);
+            assert_eq!(buffer.swap_and_take(), Miss);
+        }
+
+        #[test]
+        fn test_into_wrapper() {
+            assert_eq!(MyWrapper::from(Hit(1)), MyWrapper(Some(1)));
+            assert_eq!(MyWrapper::from(Cached::<u32>::Miss), MyWrapper(None));
+        }
+
+        #[test]
+        fn test_eq_inner() {
+            assert_eq!(Hit(42), 42);
+            assert_ne!(Hit(42), 41);
+            assert_ne!(Cached::<u32>::Miss, 42);
+            assert_ne!(Cached::<u32>::Miss, 0);
+            assert_eq!(Hit(42), Hit(42));
+        }
+
+        #[test]
+        fn test_ring_push() {
+            let mut ring = RingSlot::<u32, 3>::new();
+            assert_eq!(ring.push(1), Miss);
+            assert_eq!(ring.push(2), Miss);
+            assert_eq!(ring.push(3), Miss);
+            assert_eq!(ring.push(4), Hit(1));
+            assert_eq!(ring.push(5), Hit(2));
+            assert_eq!(ring.push(6), Hit(3));
+            assert_eq!(ring.push(7), Hit(4));
+            assert_eq!(ring.slots(), &[Hit(7), Hit(5), Hit(6)]);
+        }
+
+        #[test]
+        fn test_ring_push_zero_capacity() {
+            let mut ring = RingSlot::<u32, 0>::default();
+            assert_eq!(ring.push(1), Hit(1));
+        }
+    }
+
+    #[allow(dead_code)]
+    mod shadowed_result {
+        pub type Result<T> = core::result::Result<T, ()>;
+
+        option_like!(
+            #[derive(Eq, PartialEq, Debug)]
+            enum Cached<T> {
+                Hit(T),
+                Miss,
+            }
+
+            is_some => is_hit
+            is_none => is_miss
+        );
+
+        #[test]
+        fn test_shadowed_result() {
+            let result: Result<Cached<u32>> = Hit(1).and_then_result(|x| Ok(Hit(x + 1)));
+            assert_eq!(result, Ok(Hit(2)));
+        }
     }
 }
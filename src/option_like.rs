@@ -0,0 +1,24 @@
+/// A type that behaves like `Option<Self::Value>`.
+///
+/// Implemented for `Option<T>` and for every enum created by [`option_like!`](crate::option_like).
+pub trait OptionLike: Sized {
+    type Value;
+
+    fn into_option(self) -> Option<Self::Value>;
+
+    fn as_option(&self) -> Option<&Self::Value>;
+}
+
+impl<T> OptionLike for Option<T> {
+    type Value = T;
+
+    #[inline]
+    fn into_option(self) -> Option<T> {
+        self
+    }
+
+    #[inline]
+    fn as_option(&self) -> Option<&T> {
+        self.as_ref()
+    }
+}
@@ -0,0 +1,27 @@
+use crate::OptionLike;
+
+/// Extension methods for iterators over [`OptionLike`] values.
+pub trait OptionLikeIteratorExt: Iterator<Item: OptionLike> + Sized {
+    /// Yields the inner values, skipping the empty ones.
+    fn hits(self) -> impl Iterator<Item = <Self::Item as OptionLike>::Value> {
+        self.filter_map(OptionLike::into_option)
+    }
+
+    /// Yields the inner values transformed by `f`, skipping the empty ones.
+    fn map_hits<U, F>(self, f: F) -> impl Iterator<Item = U>
+    where
+        F: FnMut(<Self::Item as OptionLike>::Value) -> U,
+    {
+        self.hits().map(f)
+    }
+
+    /// Reduces the inner values with `f`, skipping the empty ones.
+    fn reduce_hits<F>(self, f: F) -> Option<<Self::Item as OptionLike>::Value>
+    where
+        F: FnMut(<Self::Item as OptionLike>::Value, <Self::Item as OptionLike>::Value) -> <Self::Item as OptionLike>::Value,
+    {
+        self.hits().reduce(f)
+    }
+}
+
+impl<I> OptionLikeIteratorExt for I where I: Iterator<Item: OptionLike> {}
@@ -0,0 +1,18 @@
+use core::fmt::{Debug, Formatter, Result};
+
+/// Formats the variant name of an option-like value without requiring the inner value to implement `Debug`.
+#[derive(Clone, Copy)]
+pub struct VariantDebug {
+    pub name: &'static str,
+    pub has_value: bool,
+}
+
+impl Debug for VariantDebug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if self.has_value {
+            f.debug_tuple(self.name).finish_non_exhaustive()
+        } else {
+            f.write_str(self.name)
+        }
+    }
+}